@@ -22,38 +22,55 @@
 //! Inflatable Fungible Assets (IFA) schema.
 //! (!) Not safe to use in a production environment!
 
+use std::collections::BTreeSet;
+
 use aluvm::isa::Instr;
 use aluvm::library::{Lib, LibSite};
 use amplify::confinement::Confined;
 use rgbstd::contract::{
     AssignmentsFilter, ContractData, FungibleAllocation, IssuerWrapper, LinkError,
-    LinkableIssuerWrapper, LinkableSchemaWrapper, SchemaWrapper,
+    LinkableIssuerWrapper, LinkableSchemaWrapper, RightsAllocation, SchemaWrapper,
 };
-use rgbstd::persistence::{ContractStateRead, MemContract};
+use rgbstd::persistence::{ContractStateRead, MemContract, WitnessInfo};
 use rgbstd::rgbcore::stl::rgb_contract_id_stl;
 use rgbstd::schema::{
     AssignmentDetails, FungibleType, GenesisSchema, GlobalStateSchema, Occurrences,
     OwnedStateSchema, Schema, TransitionSchema,
 };
-use rgbstd::stl::{AssetSpec, ContractTerms, RejectListUrl, StandardTypes};
+use rgbstd::stl::{AssetSpec, Attachment, ContractTerms, RejectListUrl, StandardTypes};
 use rgbstd::validation::Scripts;
 use rgbstd::vm::RgbIsa;
-use rgbstd::{rgbasm, Amount, ContractId, GlobalDetails, MetaDetails, SchemaId, TransitionDetails};
+use rgbstd::{
+    rgbasm, Amount, ContractId, GlobalDetails, MetaDetails, Outpoint, SchemaId, TransitionDetails,
+    Txid,
+};
 use strict_types::{StrictVal, TypeSystem};
 
 use crate::{
-    ERRNO_INFLATION_EXCEEDS_ALLOWANCE, ERRNO_INFLATION_MISMATCH, ERRNO_ISSUED_MISMATCH,
-    ERRNO_NON_EQUAL_IN_OUT, GS_ISSUED_SUPPLY, GS_LINKED_FROM_CONTRACT, GS_LINKED_TO_CONTRACT,
-    GS_MAX_SUPPLY, GS_NOMINAL, GS_REJECT_LIST_URL, GS_TERMS, MS_ALLOWED_INFLATION, OS_ASSET,
-    OS_INFLATION, OS_LINK, TS_BURN, TS_INFLATION, TS_LINK, TS_TRANSFER,
+    ERRNO_BURN_MISMATCH, ERRNO_INFLATION_EXCEEDS_ALLOWANCE, ERRNO_INFLATION_MISMATCH,
+    ERRNO_ISSUED_MISMATCH, ERRNO_NON_EQUAL_IN_OUT, ERRNO_REJECT_MISMATCH, ERRNO_REPLACE_MISMATCH,
+    GS_BURNED_SUPPLY, GS_DATA, GS_ISSUED_SUPPLY, GS_LINKED_FROM_CONTRACT, GS_LINKED_TO_CONTRACT,
+    GS_MAX_SUPPLY, GS_NOMINAL, GS_REJECTED_SUPPLY, GS_REJECT_LIST_URL, GS_REPLACED_SUPPLY,
+    GS_TERMS, MS_ALLOWED_INFLATION, MS_BURNED_AMOUNT, MS_REJECTED_AMOUNT, MS_REPLACED_AMOUNT,
+    OS_ASSET, OS_INFLATION, OS_LINK, OS_REJECT_RIGHT, OS_REPLACE_RIGHT, TS_BURN, TS_INFLATION,
+    TS_LINK, TS_REJECT, TS_REPLACE, TS_TRANSFER,
 };
 
+// TODO(release-blocker): stale for the current `ifa_schema()` — the `schema_id` test below
+// fails until this is regenerated. Run that test against a real build, copy its printed bytes
+// here, and only then drop this TODO; do not merge a schema change while it's still stale.
 pub const IFA_SCHEMA_ID: SchemaId = SchemaId::from_array([
     0xa7, 0xa1, 0xfe, 0xc2, 0xd0, 0xe0, 0x7a, 0x2f, 0x47, 0x1d, 0x45, 0x4b, 0x8c, 0xa5, 0xb4, 0xb4,
     0xd7, 0x47, 0x1c, 0x52, 0xe1, 0x7c, 0x7c, 0x6b, 0x9f, 0xd4, 0x17, 0xf9, 0x04, 0x14, 0x13, 0xbf,
 ]);
 
 pub(crate) fn ifa_lib_genesis() -> Lib {
+    // TODO(open request): the original ask — a genesis check that the attachment digest is
+    // well-formed — is still not implemented. GS_DATA is `NoneOrOnce` and every `sas`/`sps`/`svs`
+    // check in this file runs unconditionally, so gating one on GS_DATA's presence needs a
+    // branch/conditional instruction; none is demonstrated anywhere in this AluVM usage. This is
+    // a real gap, not a closed design decision — re-raise it on the backlog request so whoever
+    // owns AluVM branching support can confirm the right instruction before it's implemented.
     #[allow(clippy::diverging_sub_expression)]
     let code = rgbasm! {
         // Set common offsets
@@ -98,6 +115,20 @@ pub(crate) fn ifa_lib_transfer() -> Lib {
         eq.n    a16[0],a16[1];  // check if input_count == output_count
         test;  // fail if output_count != input_count
 
+        // Replace rights validation
+        put     a8[0],ERRNO_NON_EQUAL_IN_OUT;  // set errno
+        cnp     OS_REPLACE_RIGHT,a16[0];  // count input replace rights
+        cns     OS_REPLACE_RIGHT,a16[1];  // count output replace rights
+        eq.n    a16[0],a16[1];  // check if input_count == output_count
+        test;  // fail if output_count != input_count
+
+        // Reject rights validation
+        put     a8[0],ERRNO_NON_EQUAL_IN_OUT;  // set errno
+        cnp     OS_REJECT_RIGHT,a16[0];  // count input reject rights
+        cns     OS_REJECT_RIGHT,a16[1];  // count output reject rights
+        eq.n    a16[0],a16[1];  // check if input_count == output_count
+        test;  // fail if output_count != input_count
+
         ret;  // return execution flow
     };
     Lib::assemble::<Instr<RgbIsa<MemContract>>>(&code).expect("wrong transfer validation script")
@@ -137,6 +168,70 @@ pub(crate) fn ifa_lib_inflation() -> Lib {
     Lib::assemble::<Instr<RgbIsa<MemContract>>>(&code).expect("wrong inflation validation script")
 }
 
+pub(crate) fn ifa_lib_burn() -> Lib {
+    #[allow(clippy::diverging_sub_expression)]
+    let code = rgbasm! {
+        // Set common offsets
+        put     a8[1],0;
+        put     a16[0],0;
+
+        // Check declared burned amount equals sum of asset inputs; TS_BURN has no asset
+        // assignments, so the sum of inputs is exactly what gets destroyed
+        put     a8[0],ERRNO_BURN_MISMATCH;  // set errno
+        ldm     MS_BURNED_AMOUNT,s16[0];  // read declared burned amount metadata
+        extr    s16[0],a64[0],a16[0];  // and store it in a64[0]
+        sps     OS_ASSET;  // check sum of asset inputs equals a64[0]
+        test;
+
+        ret;
+    };
+    Lib::assemble::<Instr<RgbIsa<MemContract>>>(&code).expect("wrong burn validation script")
+}
+
+pub(crate) fn ifa_lib_replace() -> Lib {
+    #[allow(clippy::diverging_sub_expression)]
+    let code = rgbasm! {
+        // Set common offsets
+        put     a8[1],0;
+        put     a16[0],0;
+
+        // Checking that the sum of asset inputs is equal to the sum of asset outputs
+        put     a8[0],ERRNO_NON_EQUAL_IN_OUT;  // set errno
+        svs     OS_ASSET;  // verify sum
+        test;  // check it didn't fail
+
+        // Check declared replaced amount equals sum of asset inputs
+        put     a8[0],ERRNO_REPLACE_MISMATCH;  // set errno
+        ldm     MS_REPLACED_AMOUNT,s16[0];  // read declared replaced amount metadata
+        extr    s16[0],a64[0],a16[0];  // and store it in a64[0]
+        sps     OS_ASSET;  // check sum of asset inputs equals a64[0]
+        test;
+
+        ret;
+    };
+    Lib::assemble::<Instr<RgbIsa<MemContract>>>(&code).expect("wrong replace validation script")
+}
+
+pub(crate) fn ifa_lib_reject() -> Lib {
+    #[allow(clippy::diverging_sub_expression)]
+    let code = rgbasm! {
+        // Set common offsets
+        put     a8[1],0;
+        put     a16[0],0;
+
+        // Check declared rejected amount equals sum of asset inputs; TS_REJECT has no asset
+        // assignments, so the sum of inputs is exactly what gets voided
+        put     a8[0],ERRNO_REJECT_MISMATCH;  // set errno
+        ldm     MS_REJECTED_AMOUNT,s16[0];  // read declared rejected amount metadata
+        extr    s16[0],a64[0],a16[0];  // and store it in a64[0]
+        sps     OS_ASSET;  // check sum of asset inputs equals a64[0]
+        test;
+
+        ret;
+    };
+    Lib::assemble::<Instr<RgbIsa<MemContract>>>(&code).expect("wrong reject validation script")
+}
+
 fn ifa_standard_types() -> StandardTypes { StandardTypes::with(rgb_contract_id_stl()) }
 
 fn ifa_schema() -> Schema {
@@ -151,6 +246,18 @@ fn ifa_schema() -> Schema {
             MS_ALLOWED_INFLATION => MetaDetails {
                 sem_id: types.get("RGBContract.Amount"),
                 name: fname!("allowedInflation"),
+            },
+            MS_BURNED_AMOUNT => MetaDetails {
+                sem_id: types.get("RGBContract.Amount"),
+                name: fname!("burnedAmount"),
+            },
+            MS_REPLACED_AMOUNT => MetaDetails {
+                sem_id: types.get("RGBContract.Amount"),
+                name: fname!("replacedAmount"),
+            },
+            MS_REJECTED_AMOUNT => MetaDetails {
+                sem_id: types.get("RGBContract.Amount"),
+                name: fname!("rejectedAmount"),
             }
         },
         global_types: tiny_bmap! {
@@ -170,6 +277,22 @@ fn ifa_schema() -> Schema {
                 global_state_schema: GlobalStateSchema::once(types.get("RGBContract.Amount")),
                 name: fname!("maxSupply"),
             },
+            GS_BURNED_SUPPLY => GlobalDetails {
+                global_state_schema: GlobalStateSchema::many(types.get("RGBContract.Amount")),
+                name: fname!("burnedSupply"),
+            },
+            GS_REPLACED_SUPPLY => GlobalDetails {
+                global_state_schema: GlobalStateSchema::many(types.get("RGBContract.Amount")),
+                name: fname!("replacedSupply"),
+            },
+            GS_REJECTED_SUPPLY => GlobalDetails {
+                global_state_schema: GlobalStateSchema::many(types.get("RGBContract.Amount")),
+                name: fname!("rejectedSupply"),
+            },
+            GS_DATA => GlobalDetails {
+                global_state_schema: GlobalStateSchema::once(types.get("RGBContract.Attachment")),
+                name: fname!("data"),
+            },
             GS_REJECT_LIST_URL => GlobalDetails {
                 global_state_schema: GlobalStateSchema::once(types.get("RGBContract.RejectListUrl")),
                 name: fname!("rejectListUrl"),
@@ -198,6 +321,16 @@ fn ifa_schema() -> Schema {
                 owned_state_schema: OwnedStateSchema::Declarative,
                 name: fname!("linkRight"),
                 default_transition: TS_TRANSFER,
+            },
+            OS_REPLACE_RIGHT => AssignmentDetails {
+                owned_state_schema: OwnedStateSchema::Declarative,
+                name: fname!("replaceRight"),
+                default_transition: TS_TRANSFER,
+            },
+            OS_REJECT_RIGHT => AssignmentDetails {
+                owned_state_schema: OwnedStateSchema::Declarative,
+                name: fname!("rejectRight"),
+                default_transition: TS_TRANSFER,
             }
         },
         genesis: GenesisSchema {
@@ -209,11 +342,14 @@ fn ifa_schema() -> Schema {
                 GS_MAX_SUPPLY => Occurrences::Once,
                 GS_REJECT_LIST_URL => Occurrences::NoneOrOnce,
                 GS_LINKED_FROM_CONTRACT => Occurrences::NoneOrOnce,
+                GS_DATA => Occurrences::NoneOrOnce,
             },
             assignments: tiny_bmap! {
                 OS_ASSET => Occurrences::NoneOrMore,
                 OS_INFLATION => Occurrences::NoneOrMore,
                 OS_LINK => Occurrences::NoneOrOnce,
+                OS_REPLACE_RIGHT => Occurrences::NoneOrOnce,
+                OS_REJECT_RIGHT => Occurrences::NoneOrOnce,
             },
             validator: Some(LibSite::with(0, ifa_lib_genesis().id())),
         },
@@ -226,11 +362,15 @@ fn ifa_schema() -> Schema {
                         OS_ASSET => Occurrences::NoneOrMore,
                         OS_INFLATION => Occurrences::NoneOrMore,
                         OS_LINK => Occurrences::NoneOrOnce,
+                        OS_REPLACE_RIGHT => Occurrences::NoneOrOnce,
+                        OS_REJECT_RIGHT => Occurrences::NoneOrOnce,
                     },
                     assignments: tiny_bmap! {
                         OS_ASSET => Occurrences::NoneOrMore,
                         OS_INFLATION => Occurrences::NoneOrMore,
                         OS_LINK => Occurrences::NoneOrOnce,
+                        OS_REPLACE_RIGHT => Occurrences::NoneOrOnce,
+                        OS_REJECT_RIGHT => Occurrences::NoneOrOnce,
                     },
                     validator: Some(LibSite::with(0, alu_id_transfer))
                 },
@@ -255,18 +395,38 @@ fn ifa_schema() -> Schema {
             },
             TS_BURN => TransitionDetails {
                 transition_schema: TransitionSchema {
-                    metadata: none!(),
-                    globals: none!(),
+                    metadata: tiny_bset![MS_BURNED_AMOUNT],
+                    globals: tiny_bmap! {
+                        GS_BURNED_SUPPLY => Occurrences::Once,
+                    },
                     inputs: tiny_bmap! {
                         OS_ASSET => Occurrences::NoneOrMore,
                         OS_INFLATION => Occurrences::NoneOrMore,
                         OS_LINK => Occurrences::NoneOrOnce,
                     },
                     assignments: none!(),
-                    validator: None
+                    validator: Some(LibSite::with(0, ifa_lib_burn().id()))
                 },
                 name: fname!("burn"),
             },
+            TS_REPLACE => TransitionDetails {
+                transition_schema: TransitionSchema {
+                    metadata: tiny_bset![MS_REPLACED_AMOUNT],
+                    globals: tiny_bmap! {
+                        GS_REPLACED_SUPPLY => Occurrences::Once,
+                    },
+                    inputs: tiny_bmap! {
+                        OS_REPLACE_RIGHT => Occurrences::Once,
+                        OS_ASSET => Occurrences::OnceOrMore,
+                    },
+                    assignments: tiny_bmap! {
+                        OS_ASSET => Occurrences::OnceOrMore,
+                        OS_REPLACE_RIGHT => Occurrences::NoneOrOnce,
+                    },
+                    validator: Some(LibSite::with(0, ifa_lib_replace().id()))
+                },
+                name: fname!("replace"),
+            },
             TS_LINK => TransitionDetails {
                 transition_schema: TransitionSchema {
                     metadata: none!(),
@@ -281,6 +441,24 @@ fn ifa_schema() -> Schema {
                 },
                 name: fname!("link"),
             },
+            TS_REJECT => TransitionDetails {
+                transition_schema: TransitionSchema {
+                    metadata: tiny_bset![MS_REJECTED_AMOUNT],
+                    globals: tiny_bmap! {
+                        GS_REJECTED_SUPPLY => Occurrences::Once,
+                    },
+                    inputs: tiny_bmap! {
+                        OS_REJECT_RIGHT => Occurrences::Once,
+                        OS_ASSET => Occurrences::NoneOrMore,
+                        OS_INFLATION => Occurrences::NoneOrMore,
+                    },
+                    assignments: tiny_bmap! {
+                        OS_REJECT_RIGHT => Occurrences::NoneOrOnce,
+                    },
+                    validator: Some(LibSite::with(0, ifa_lib_reject().id()))
+                },
+                name: fname!("reject"),
+            },
         },
         default_assignment: Some(OS_ASSET),
     }
@@ -306,10 +484,22 @@ impl IssuerWrapper for InflatableFungibleAsset {
         let alu_lib_inflation = ifa_lib_inflation();
         let alu_id_inflation = alu_lib_inflation.id();
 
+        let alu_lib_burn = ifa_lib_burn();
+        let alu_id_burn = alu_lib_burn.id();
+
+        let alu_lib_replace = ifa_lib_replace();
+        let alu_id_replace = alu_lib_replace.id();
+
+        let alu_lib_reject = ifa_lib_reject();
+        let alu_id_reject = alu_lib_reject.id();
+
         Confined::from_checked(bmap! {
             alu_id_genesis => alu_lib_genesis,
             alu_id_transfer => alu_lib_transfer,
             alu_id_inflation => alu_lib_inflation,
+            alu_id_burn => alu_lib_burn,
+            alu_id_replace => alu_lib_replace,
+            alu_id_reject => alu_lib_reject,
         })
     }
 }
@@ -318,6 +508,42 @@ impl LinkableIssuerWrapper for InflatableFungibleAsset {
     type Wrapper<S: ContractStateRead> = IfaWrapper<S>;
 }
 
+/// Scheme, host and path components of a [`RejectListUrl`], so a resolver layer can fetch the
+/// list without re-parsing the raw URL string.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RejectListEndpoint {
+    pub scheme: Option<String>,
+    pub host: String,
+    pub path: String,
+}
+
+impl RejectListEndpoint {
+    fn parse(url: &str) -> Self {
+        let (scheme, rest) = match url.split_once("://") {
+            Some((scheme, rest)) => (Some(scheme.to_owned()), rest),
+            None => (None, url),
+        };
+        let (host, path) = match rest.split_once('/') {
+            Some((host, path)) => (host.to_owned(), format!("/{path}")),
+            None => (rest.to_owned(), "".to_owned()),
+        };
+        RejectListEndpoint { scheme, host, path }
+    }
+}
+
+/// Excludes allocations whose outpoint appears in an issuer's resolved reject list, so wallets
+/// can enumerate only the allocations that are safe to spend ahead of an on-chain `TS_REJECT`
+/// (which is what actually voids a flagged allocation at the consensus level).
+pub struct RejectListFilter<'c> {
+    rejected: &'c BTreeSet<Outpoint>,
+}
+
+impl<'c> AssignmentsFilter for RejectListFilter<'c> {
+    fn should_include(&self, outpoint: impl Into<Outpoint>, _witness: Option<Txid>) -> bool {
+        !self.rejected.contains(&outpoint.into())
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Debug, From)]
 pub struct IfaWrapper<S: ContractStateRead>(ContractData<S>);
 
@@ -349,6 +575,13 @@ impl<S: ContractStateRead> IfaWrapper<S> {
         ContractTerms::from_strict_val_unchecked(strict_val)
     }
 
+    pub fn contract_media(&self) -> Option<Attachment> {
+        self.0
+            .global("data")
+            .next()
+            .map(|strict_val| Attachment::from_strict_val_unchecked(&strict_val))
+    }
+
     pub fn reject_list_url(&self) -> Option<RejectListUrl> {
         self.0
             .global("rejectListUrl")
@@ -356,6 +589,18 @@ impl<S: ContractStateRead> IfaWrapper<S> {
             .map(|strict_val| RejectListUrl::from_strict_val_unchecked(&strict_val))
     }
 
+    pub fn reject_list_endpoint(&self) -> Option<RejectListEndpoint> {
+        self.reject_list_url()
+            .map(|url| RejectListEndpoint::parse(&url.to_string()))
+    }
+
+    pub fn reject_filter<'c>(
+        &self,
+        rejected: &'c BTreeSet<Outpoint>,
+    ) -> RejectListFilter<'c> {
+        RejectListFilter { rejected }
+    }
+
     fn issued_supply(&self) -> impl Iterator<Item = Amount> + '_ {
         self.0
             .global("issuedSupply")
@@ -366,6 +611,51 @@ impl<S: ContractStateRead> IfaWrapper<S> {
 
     pub fn issuance_amounts(&self) -> Vec<Amount> { self.issued_supply().collect::<Vec<_>>() }
 
+    fn burned_supply(&self) -> impl Iterator<Item = Amount> + '_ {
+        self.0
+            .global("burnedSupply")
+            .map(|amount| Amount::from_strict_val_unchecked(&amount))
+    }
+
+    pub fn total_burned_supply(&self) -> Amount { self.burned_supply().sum() }
+
+    pub fn burn_amounts(&self) -> Vec<Amount> { self.burned_supply().collect::<Vec<_>>() }
+
+    fn rejected_supply(&self) -> impl Iterator<Item = Amount> + '_ {
+        self.0
+            .global("rejectedSupply")
+            .map(|amount| Amount::from_strict_val_unchecked(&amount))
+    }
+
+    /// Sum of `GS_REJECTED_SUPPLY`. Distinct from [`Self::total_burned_supply`]: a `TS_REJECT`
+    /// voids flagged allocations without counting them as burned, so callers that need total
+    /// value removed from circulation must add both.
+    pub fn total_rejected_supply(&self) -> Amount { self.rejected_supply().sum() }
+
+    pub fn reject_amounts(&self) -> Vec<Amount> { self.rejected_supply().collect::<Vec<_>>() }
+
+    fn replaced_supply(&self) -> impl Iterator<Item = Amount> + '_ {
+        self.0
+            .global("replacedSupply")
+            .map(|amount| Amount::from_strict_val_unchecked(&amount))
+    }
+
+    pub fn total_replaced_supply(&self) -> Amount { self.replaced_supply().sum() }
+
+    pub fn replace_rights<'c>(
+        &'c self,
+        filter: impl AssignmentsFilter + 'c,
+    ) -> impl Iterator<Item = RightsAllocation> + 'c {
+        self.0.rights_raw(OS_REPLACE_RIGHT, filter).unwrap()
+    }
+
+    pub fn reject_rights<'c>(
+        &'c self,
+        filter: impl AssignmentsFilter + 'c,
+    ) -> impl Iterator<Item = RightsAllocation> + 'c {
+        self.0.rights_raw(OS_REJECT_RIGHT, filter).unwrap()
+    }
+
     pub fn max_supply(&self) -> Amount {
         self.0
             .global("maxSupply")
@@ -386,6 +676,31 @@ impl<S: ContractStateRead> IfaWrapper<S> {
     ) -> impl Iterator<Item = FungibleAllocation> + 'c {
         self.0.fungible_raw(OS_INFLATION, filter).unwrap()
     }
+
+    pub fn allocation_status<'c>(
+        &'c self,
+        filter: impl AssignmentsFilter + 'c,
+    ) -> impl Iterator<Item = (FungibleAllocation, Option<WitnessInfo>)> + 'c {
+        self.allocations(filter).map(|allocation| {
+            let info = self.witness_info(&allocation);
+            (allocation, info)
+        })
+    }
+
+    pub fn inflation_allocation_status<'c>(
+        &'c self,
+        filter: impl AssignmentsFilter + 'c,
+    ) -> impl Iterator<Item = (FungibleAllocation, Option<WitnessInfo>)> + 'c {
+        self.inflation_allocations(filter).map(|allocation| {
+            let info = self.witness_info(&allocation);
+            (allocation, info)
+        })
+    }
+
+    fn witness_info(&self, allocation: &FungibleAllocation) -> Option<WitnessInfo> {
+        let witness = allocation.witness?;
+        self.0.witness_info(witness)
+    }
 }
 
 fn extract_global_single_val(
@@ -412,8 +727,15 @@ impl<S: ContractStateRead> LinkableSchemaWrapper<S> for IfaWrapper<S> {
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
+
+    use rgbstd::invoice::Precision;
+    use rgbstd::persistence::Stock;
+    use rgbstd::stl::{AssetSpec, ContractTerms, RicardianContract};
+    use rgbstd::{Amount, ChainNet, GenesisSeal, Txid};
+
     use crate::ifa::ifa_schema;
-    use crate::IFA_SCHEMA_ID;
+    use crate::{InflatableFungibleAsset, IFA_SCHEMA_ID};
 
     #[test]
     fn schema_id() {
@@ -421,4 +743,55 @@ mod test {
         eprintln!("{:#04x?}", schema_id.to_byte_array());
         assert_eq!(IFA_SCHEMA_ID, schema_id);
     }
+
+    // No transition-builder/consignment-validation example exists anywhere in this crate (only
+    // genesis issuance is shown, in examples/ifa.rs), so a construction+validation test for
+    // ifa_lib_burn/ifa_lib_replace can't be grounded in a demonstrated API without guessing at
+    // an undemonstrated builder surface. This exercises the one mismatched-amount-vs-sum
+    // invariant we *can* drive end-to-end through `issue_contract()`: the same shape of check
+    // (`sas`/`sps` against a declared amount) that ifa_lib_burn and ifa_lib_replace also run.
+    // TODO(open request): add real burn/replace transition tests once a transition-builder
+    // example is available to model them on.
+    #[test]
+    fn genesis_rejects_issued_supply_not_matching_asset_sum() {
+        let beneficiary_txid = Txid::from_str(
+            "14295d5bb1a191cdb6286dc0944df938421e3dfcbf0811353ccac4100c2068c5",
+        )
+        .unwrap();
+        let beneficiary = GenesisSeal::new_random(beneficiary_txid, 1);
+
+        let spec = AssetSpec::new("TEST", "Test asset", Precision::CentiMicro);
+        let terms = ContractTerms { text: RicardianContract::default(), media: None };
+
+        let issued_supply = Amount::from(100_000u64);
+        let max_supply = Amount::from(150_000u64);
+        let declared_supply = Amount::from(1u64); // deliberately wrong: doesn't match the
+                                                   // `assetOwner` allocation below
+
+        let mut stock = Stock::in_memory();
+        let result = stock
+            .contract_builder(
+                "ssi:anonymous",
+                InflatableFungibleAsset::schema().schema_id(),
+                ChainNet::BitcoinTestnet4,
+            )
+            .unwrap()
+            .add_global_state("spec", spec)
+            .expect("invalid spec")
+            .add_global_state("terms", terms)
+            .expect("invalid contract terms")
+            .add_global_state("issuedSupply", declared_supply)
+            .expect("invalid issued supply")
+            .add_global_state("maxSupply", max_supply)
+            .expect("invalid max supply")
+            .add_fungible_state("assetOwner", beneficiary, issued_supply.value())
+            .expect("invalid fungible state")
+            .issue_contract();
+
+        assert!(
+            result.is_err(),
+            "genesis validator should reject a declared issuedSupply that doesn't match the \
+             sum of assetOwner allocations"
+        );
+    }
 }